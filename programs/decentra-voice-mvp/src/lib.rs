@@ -1,5 +1,4 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 
 declare_id!("8kNH8KYr2c6karBWHUcSos3qWyWC4eWQ91Pr7M9onsdh");
 
@@ -9,22 +8,40 @@ pub mod decentra_voice_mvp {
 
     pub fn create_channel(
         ctx: Context<CreateChannel>,
+        channel_id: String,
         name: String,
+        private: bool,
+        max_participants: u32,
     ) -> Result<String> {
         let channel = &mut ctx.accounts.channel;
         let clock = Clock::get()?;
-        
-        // Generate human-friendly 8-character channel ID
-        let channel_id = generate_channel_id(&clock.unix_timestamp);
-        
+
+        // The caller supplies the human-friendly ID. The account constraints
+        // have already used it as the PDA seed, and `init` has guaranteed
+        // uniqueness by failing if the derived address is occupied; a rejected
+        // ID here aborts the whole transaction, so no channel is left behind.
+        require!(is_valid_channel_id(&channel_id), ChannelError::InvalidChannelId);
+
         // Store channel info
         channel.id = channel_id.clone();
         channel.name = name;
         channel.creator = ctx.accounts.user.key();
         channel.created_at = clock.unix_timestamp;
         channel.active = true;
-        channel.participant_count = 0;
-        
+        channel.private = private;
+        channel.max_participants = max_participants;
+        channel.topic = String::new();
+        channel.topic_updated_at = 0;
+
+        // The creator joins their own channel as `Owner`; counting this
+        // membership keeps `participant_count` and the `getProgramAccounts`
+        // member list honest from the first block.
+        let membership = &mut ctx.accounts.membership;
+        membership.channel_id = channel_id.clone();
+        membership.user = ctx.accounts.user.key();
+        membership.role = Role::Owner;
+        channel.participant_count = 1;
+
         msg!("Channel created: {} ({})", channel.name, channel.id);
         
         Ok(channel_id)
@@ -34,12 +51,44 @@ pub mod decentra_voice_mvp {
         ctx: Context<JoinChannel>,
     ) -> Result<ChannelInfo> {
         let channel = &mut ctx.accounts.channel;
-        
+
         require!(channel.active, ChannelError::ChannelInactive);
-        
+
+        // A zero cap means unlimited; otherwise the channel refuses joins once
+        // it is at capacity.
+        require!(
+            channel.max_participants == 0
+                || channel.participant_count < channel.max_participants,
+            ChannelError::ChannelFull
+        );
+
+        // A live ban record for this signer blocks the join outright.
+        require!(
+            ctx.accounts.ban_record.data_is_empty(),
+            ChannelError::Unauthorized
+        );
+
+        // Private channels require a live invitation for the joining signer; it
+        // is consumed (closed, rent refunded to the inviter) by the account
+        // constraint once the join succeeds.
+        if channel.private {
+            require!(
+                ctx.accounts.invitation.is_some(),
+                ChannelError::Unauthorized
+            );
+        }
+
+        // Record the membership; the PDA's existence is what makes the join
+        // idempotent, so a repeated call fails at `init` rather than inflating
+        // the count.
+        let membership = &mut ctx.accounts.membership;
+        membership.channel_id = channel.id.clone();
+        membership.user = ctx.accounts.user.key();
+        membership.role = Role::Member;
+
         // Update participant count
         channel.participant_count += 1;
-        
+
         let channel_info = ChannelInfo {
             id: channel.id.clone(),
             name: channel.name.clone(),
@@ -47,6 +96,7 @@ pub mod decentra_voice_mvp {
             created_at: channel.created_at,
             participant_count: channel.participant_count,
             active: channel.active,
+            topic: channel.topic.clone(),
         };
         
         msg!("User joined channel: {} ({})", channel.name, channel.id);
@@ -58,17 +108,200 @@ pub mod decentra_voice_mvp {
         ctx: Context<LeaveChannel>,
     ) -> Result<()> {
         let channel = &mut ctx.accounts.channel;
-        
-        // Decrease participant count
+
+        // The membership PDA is closed by the account constraint; only mirror
+        // that in the count so leave stays symmetric with join.
         if channel.participant_count > 0 {
             channel.participant_count -= 1;
         }
-        
+
         msg!("User left channel: {} ({})", channel.name, channel.id);
         
         Ok(())
     }
     
+    pub fn set_capacity(
+        ctx: Context<SetCapacity>,
+        max_participants: u32,
+    ) -> Result<()> {
+        // Only the channel owner may resize the room.
+        require!(
+            ctx.accounts.caller.key() == ctx.accounts.channel.creator,
+            ChannelError::Unauthorized
+        );
+
+        let channel = &mut ctx.accounts.channel;
+        // A non-zero cap may not be set below the current membership; 0 lifts
+        // the limit entirely.
+        require!(
+            max_participants == 0 || max_participants >= channel.participant_count,
+            ChannelError::ChannelFull
+        );
+        channel.max_participants = max_participants;
+
+        msg!("Channel capacity set to {}: {}", max_participants, channel.id);
+
+        Ok(())
+    }
+
+    pub fn set_topic(
+        ctx: Context<SetTopic>,
+        topic: String,
+    ) -> Result<()> {
+        // The creator and moderators share the topic-setting privilege.
+        require!(
+            caller_can_moderate(
+                &ctx.accounts.channel,
+                ctx.accounts.caller.key(),
+                ctx.accounts.caller_membership.as_ref(),
+            ),
+            ChannelError::Unauthorized
+        );
+        // The account only reserves 4 + 256 bytes for the topic; reject
+        // oversized input up front rather than failing serialization later.
+        require!(topic.len() <= 256, ChannelError::TopicTooLong);
+
+        let clock = Clock::get()?;
+        let channel = &mut ctx.accounts.channel;
+        channel.topic = topic;
+        channel.topic_updated_at = clock.unix_timestamp;
+
+        msg!("Channel topic updated: {}", channel.id);
+
+        Ok(())
+    }
+
+    pub fn add_moderator(
+        ctx: Context<AddModerator>,
+    ) -> Result<()> {
+        // Only the channel owner may change the moderator roster.
+        require!(
+            ctx.accounts.caller.key() == ctx.accounts.channel.creator,
+            ChannelError::Unauthorized
+        );
+
+        let target = &mut ctx.accounts.target_membership;
+        target.role = Role::Moderator;
+
+        msg!("Moderator added: {}", target.user);
+
+        Ok(())
+    }
+
+    pub fn remove_moderator(
+        ctx: Context<RemoveModerator>,
+    ) -> Result<()> {
+        // Only the channel owner may change the moderator roster.
+        require!(
+            ctx.accounts.caller.key() == ctx.accounts.channel.creator,
+            ChannelError::Unauthorized
+        );
+
+        let target = &mut ctx.accounts.target_membership;
+        target.role = Role::Member;
+
+        msg!("Moderator removed: {}", target.user);
+
+        Ok(())
+    }
+
+    pub fn kick_participant(
+        ctx: Context<KickParticipant>,
+    ) -> Result<()> {
+        require!(
+            caller_can_moderate(
+                &ctx.accounts.channel,
+                ctx.accounts.caller.key(),
+                ctx.accounts.caller_membership.as_ref(),
+            ),
+            ChannelError::Unauthorized
+        );
+        // The owner is immune to kicks.
+        require!(
+            ctx.accounts.target_membership.user != ctx.accounts.channel.creator,
+            ChannelError::Unauthorized
+        );
+
+        let channel = &mut ctx.accounts.channel;
+        if channel.participant_count > 0 {
+            channel.participant_count -= 1;
+        }
+
+        msg!("Participant kicked: {}", ctx.accounts.target_membership.user);
+
+        Ok(())
+    }
+
+    pub fn ban_participant(
+        ctx: Context<BanParticipant>,
+    ) -> Result<()> {
+        require!(
+            caller_can_moderate(
+                &ctx.accounts.channel,
+                ctx.accounts.caller.key(),
+                ctx.accounts.caller_membership.as_ref(),
+            ),
+            ChannelError::Unauthorized
+        );
+        require!(
+            ctx.accounts.target_membership.user != ctx.accounts.channel.creator,
+            ChannelError::Unauthorized
+        );
+
+        let ban = &mut ctx.accounts.ban_record;
+        ban.channel_id = ctx.accounts.channel.id.clone();
+        ban.user = ctx.accounts.target_membership.user;
+        ban.banned_by = ctx.accounts.caller.key();
+
+        let channel = &mut ctx.accounts.channel;
+        if channel.participant_count > 0 {
+            channel.participant_count -= 1;
+        }
+
+        msg!("Participant banned: {}", ban.user);
+
+        Ok(())
+    }
+
+    pub fn invite_user(
+        ctx: Context<InviteUser>,
+    ) -> Result<()> {
+        require!(
+            caller_can_moderate(
+                &ctx.accounts.channel,
+                ctx.accounts.caller.key(),
+                ctx.accounts.caller_membership.as_ref(),
+            ),
+            ChannelError::Unauthorized
+        );
+
+        let invitation = &mut ctx.accounts.invitation;
+        invitation.channel_id = ctx.accounts.channel.id.clone();
+        invitation.invitee = ctx.accounts.invitee.key();
+        invitation.inviter = ctx.accounts.caller.key();
+
+        msg!("User invited: {}", invitation.invitee);
+
+        Ok(())
+    }
+
+    pub fn revoke_invite(
+        ctx: Context<RevokeInvite>,
+    ) -> Result<()> {
+        require!(
+            caller_can_moderate(
+                &ctx.accounts.channel,
+                ctx.accounts.caller.key(),
+                ctx.accounts.caller_membership.as_ref(),
+            ),
+            ChannelError::Unauthorized
+        );
+
+        msg!("Invitation revoked: {}", ctx.accounts.invitation.invitee);
+
+        Ok(())
+    }
+
     pub fn get_channel_info(
         ctx: Context<GetChannelInfo>,
     ) -> Result<ChannelInfo> {
@@ -81,23 +314,65 @@ pub mod decentra_voice_mvp {
             created_at: channel.created_at,
             participant_count: channel.participant_count,
             active: channel.active,
+            topic: channel.topic.clone(),
         };
         
         Ok(channel_info)
     }
 }
 
-// Helper function to generate human-friendly channel IDs
-fn generate_channel_id(timestamp: &i64) -> String {
-    // Use timestamp and some randomness to create IDs like "CHAT-1A2B"
-    let suffix = format!("{:X}", timestamp % 0xFFFF);
-    format!("CHAT-{}", &suffix[suffix.len().saturating_sub(4)..])
+// A caller may moderate if they created the channel or hold a moderator/owner
+// membership role.
+fn caller_can_moderate(
+    channel: &Channel,
+    caller: Pubkey,
+    membership: Option<&Account<'_, Membership>>,
+) -> bool {
+    if caller == channel.creator {
+        return true;
+    }
+    matches!(
+        membership.map(|m| &m.role),
+        Some(Role::Owner) | Some(Role::Moderator)
+    )
+}
+
+// Canonical derivation of a channel's on-chain address from its human-friendly
+// ID. Clients call this to locate a channel from its `CHAT-XXXX` string alone,
+// with no off-chain index.
+pub fn channel_address(channel_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"channel", channel_id.as_bytes()], &crate::ID)
+}
+
+// A channel ID must fit the PDA seed limit and stay within the `CHAT-XXXX`
+// charset so it can be typed and shared safely.
+fn is_valid_channel_id(channel_id: &str) -> bool {
+    !channel_id.is_empty()
+        && channel_id.len() <= 32
+        && channel_id
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-')
 }
 
 #[derive(Accounts)]
+#[instruction(channel_id: String)]
 pub struct CreateChannel<'info> {
-    #[account(init, payer = user, space = 8 + 64 + 256 + 32 + 8 + 1 + 4)]
+    #[account(
+        init,
+        payer = user,
+        space = 8 + 64 + 256 + 32 + 8 + 1 + 4 + 1 + 4 + 4 + 256 + 8,
+        seeds = [b"channel", channel_id.as_bytes()],
+        bump
+    )]
     pub channel: Account<'info, Channel>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + 64 + 32 + 1,
+        seeds = [b"member", channel_id.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, Membership>,
     #[account(mut)]
     pub user: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -107,13 +382,193 @@ pub struct CreateChannel<'info> {
 pub struct JoinChannel<'info> {
     #[account(mut)]
     pub channel: Account<'info, Channel>,
+    #[account(
+        init,
+        payer = user,
+        space = 8 + 64 + 32 + 1,
+        seeds = [b"member", channel.id.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, Membership>,
+    /// CHECK: ban record PDA; the join is rejected unless this address is empty.
+    #[account(
+        seeds = [b"ban", channel.id.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub ban_record: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = inviter,
+        has_one = inviter,
+        seeds = [b"invite", channel.id.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub invitation: Option<Account<'info, Invitation>>,
+    /// CHECK: rent refund recipient for a consumed invitation.
+    #[account(mut)]
+    pub inviter: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
     pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InviteUser<'info> {
+    pub channel: Account<'info, Channel>,
+    #[account(
+        seeds = [b"member", channel.id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub caller_membership: Option<Account<'info, Membership>>,
+    #[account(
+        init,
+        payer = caller,
+        space = 8 + 64 + 32 + 32,
+        seeds = [b"invite", channel.id.as_bytes(), invitee.key().as_ref()],
+        bump
+    )]
+    pub invitation: Account<'info, Invitation>,
+    /// CHECK: identifies the invited member via PDA seeds.
+    pub invitee: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeInvite<'info> {
+    pub channel: Account<'info, Channel>,
+    #[account(
+        seeds = [b"member", channel.id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub caller_membership: Option<Account<'info, Membership>>,
+    #[account(
+        mut,
+        close = caller,
+        seeds = [b"invite", channel.id.as_bytes(), invitee.key().as_ref()],
+        bump
+    )]
+    pub invitation: Account<'info, Invitation>,
+    /// CHECK: identifies the invitation being revoked via PDA seeds.
+    pub invitee: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetTopic<'info> {
+    #[account(mut)]
+    pub channel: Account<'info, Channel>,
+    #[account(
+        seeds = [b"member", channel.id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub caller_membership: Option<Account<'info, Membership>>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCapacity<'info> {
+    #[account(mut)]
+    pub channel: Account<'info, Channel>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddModerator<'info> {
+    pub channel: Account<'info, Channel>,
+    #[account(
+        mut,
+        seeds = [b"member", channel.id.as_bytes(), target.key().as_ref()],
+        bump
+    )]
+    pub target_membership: Account<'info, Membership>,
+    /// CHECK: identifies the membership being promoted via PDA seeds.
+    pub target: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveModerator<'info> {
+    pub channel: Account<'info, Channel>,
+    #[account(
+        mut,
+        seeds = [b"member", channel.id.as_bytes(), target.key().as_ref()],
+        bump
+    )]
+    pub target_membership: Account<'info, Membership>,
+    /// CHECK: identifies the membership being demoted via PDA seeds.
+    pub target: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct KickParticipant<'info> {
+    #[account(mut)]
+    pub channel: Account<'info, Channel>,
+    #[account(
+        seeds = [b"member", channel.id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub caller_membership: Option<Account<'info, Membership>>,
+    #[account(
+        mut,
+        close = target,
+        seeds = [b"member", channel.id.as_bytes(), target.key().as_ref()],
+        bump
+    )]
+    pub target_membership: Account<'info, Membership>,
+    /// CHECK: rent refund destination for the closed membership.
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BanParticipant<'info> {
+    #[account(mut)]
+    pub channel: Account<'info, Channel>,
+    #[account(
+        seeds = [b"member", channel.id.as_bytes(), caller.key().as_ref()],
+        bump
+    )]
+    pub caller_membership: Option<Account<'info, Membership>>,
+    #[account(
+        mut,
+        close = target,
+        seeds = [b"member", channel.id.as_bytes(), target.key().as_ref()],
+        bump
+    )]
+    pub target_membership: Account<'info, Membership>,
+    #[account(
+        init,
+        payer = caller,
+        space = 8 + 64 + 32 + 32,
+        seeds = [b"ban", channel.id.as_bytes(), target.key().as_ref()],
+        bump
+    )]
+    pub ban_record: Account<'info, BanRecord>,
+    /// CHECK: rent refund destination for the closed membership.
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
 pub struct LeaveChannel<'info> {
     #[account(mut)]
     pub channel: Account<'info, Channel>,
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"member", channel.id.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, Membership>,
+    #[account(mut)]
     pub user: Signer<'info>,
 }
 
@@ -130,6 +585,38 @@ pub struct Channel {
     pub created_at: i64,      // 8 bytes - timestamp
     pub active: bool,         // 1 byte - is channel active
     pub participant_count: u32, // 4 bytes - current participants
+    pub private: bool,        // 1 byte - invite-only when true
+    pub max_participants: u32, // 4 bytes - capacity cap (0 = unlimited)
+    pub topic: String,        // 4 + 256 bytes - human-readable room description
+    pub topic_updated_at: i64, // 8 bytes - when the topic was last changed
+}
+
+#[account]
+pub struct Membership {
+    pub channel_id: String, // 64 bytes - channel this membership belongs to
+    pub user: Pubkey,       // 32 bytes - the member
+    pub role: Role,         // 1 byte - privilege level within the channel
+}
+
+#[account]
+pub struct BanRecord {
+    pub channel_id: String, // 64 bytes - channel the ban applies to
+    pub user: Pubkey,       // 32 bytes - the banned participant
+    pub banned_by: Pubkey,  // 32 bytes - moderator/owner who issued the ban
+}
+
+#[account]
+pub struct Invitation {
+    pub channel_id: String, // 64 bytes - channel the invite grants access to
+    pub invitee: Pubkey,    // 32 bytes - the invited user
+    pub inviter: Pubkey,    // 32 bytes - owner/moderator who issued the invite
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum Role {
+    Owner,
+    Moderator,
+    Member,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -140,6 +627,7 @@ pub struct ChannelInfo {
     pub created_at: i64,
     pub participant_count: u32,
     pub active: bool,
+    pub topic: String,
 }
 
 #[error_code]
@@ -150,4 +638,8 @@ pub enum ChannelError {
     ChannelFull,
     #[msg("Unauthorized access")]
     Unauthorized,
+    #[msg("Channel ID is empty, too long, or has invalid characters")]
+    InvalidChannelId,
+    #[msg("Topic exceeds the maximum length")]
+    TopicTooLong,
 }